@@ -18,7 +18,7 @@
 //!
 //! ## Usage
 //!
-//! This iterator works on inclusive and exclusive ranges of `usize`. You can access it via:
+//! This iterator works on inclusive and exclusive ranges of any primitive integer type (`usize`, `u32`, `i64`, ...). You can access it via:
 //!
 //! ```rust
 //! use rlp_iter::RlpIterator;
@@ -28,10 +28,54 @@
 //! }
 //! ```
 //!
+//! Signed ranges work the same way:
+//!
+//! ```rust
+//! use rlp_iter::RlpIterator;
+//!
+//! for i in (-50..=50).rlp_iter() {
+//!     println!("{}", i);
+//! }
+//! ```
+//!
+//! `..end` and `..=end` are also accepted (treating the start as `0`), and `start..` yields an infinite space-filling stream via the separate `RlpUnboundedIterator` trait:
+//!
+//! ```rust
+//! use rlp_iter::RlpUnboundedIterator;
+//!
+//! for i in (0..).rlp_iter().take(100) {
+//!     println!("{}", i);
+//! }
+//! ```
+//!
+//! For well-spread *points* rather than 1D indices (image tile sampling, parameter-space search), `RlpIterND` samples a `D`-dimensional box with a Halton sequence. It's a low-discrepancy sampler rather than an exact permutation, so it's meant to be `.take(n)`-ed:
+//!
+//! ```rust
+//! use rlp_iter::RlpIterND;
+//!
+//! for [x, y] in RlpIterND::new([1920, 1080]).take(100) {
+//!     println!("{} {}", x, y);
+//! }
+//! ```
+//!
+//! If `range` is in the billions and you only want a representative sample rather than every value, cap the subdivision depth with `rlp_iter_with_stride()` (or `.with_stride()` on an already-built `RlpIter`, though that has already paid for a `BitVec` sized to the full range):
+//!
+//! ```rust
+//! use rlp_iter::RlpIterator;
+//!
+//! // Roughly 1024 evenly-spread indices instead of walking all 1 billion,
+//! // without ever allocating a billion-bit BitVec.
+//! for i in (0..1_000_000_000).rlp_iter_with_stride(10) {
+//!     println!("{}", i);
+//! }
+//! ```
+//!
 //! ## Overhead
 //!
 //! This requires a small constant amount of memory, plus one bit of memory per value in the sampled space (required to ensure there are no duplicate values emitted).
 //!
+//! If even that one bit per value is too much (e.g. iterating `0..=1_000_000_000`), use `rlp_iter_lowmem()` instead of `rlp_iter()`. It emits a space-filling order in O(log n) memory via a bit-reversal permutation, at the cost of being a little slower per element. Endpoints are emitted first, same as `rlp_iter()`, and both then follow a halving, space-filling order, but not the identical one: for `0..=7`, `rlp_iter()` gives `0, 7, 4, 2, 5, 1, 3, 6` while `rlp_iter_lowmem()` gives `0, 7, 4, 2, 6, 1, 5, 3`.
+//!
 //! ## License
 //!
 //! Licensed under either of
@@ -47,7 +91,11 @@
 //!
 
 use bit_vec::BitVec;
-use std::ops::{Range, RangeInclusive};
+use num_integer::Integer;
+use num_traits::{FromPrimitive, PrimInt, ToPrimitive};
+use std::collections::HashSet;
+use std::iter::FusedIterator;
+use std::ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
 
 enum State {
     Start,
@@ -56,64 +104,150 @@ enum State {
     Finished,
 }
 
-pub struct RlpIter {
-    tested: BitVec,
-    shift: usize,
-    range: usize,
-    numerator: usize,
+// `Dense` is a bit per value in the range, used by the default `rlp_iter()`.
+// `Sparse` only pays for the handful of values a stride-capped run actually
+// touches, so it's used instead of allocating a `BitVec` sized to the full
+// (possibly huge) range just to cap it down afterwards.
+enum Tested {
+    Dense(BitVec),
+    Sparse(HashSet<usize>),
+}
+
+impl Tested {
+    fn get(&self, idx: usize) -> bool {
+        match self {
+            Tested::Dense(bits) => bits.get(idx).unwrap(),
+            Tested::Sparse(seen) => seen.contains(&idx),
+        }
+    }
+
+    fn set(&mut self, idx: usize) {
+        match self {
+            Tested::Dense(bits) => bits.set(idx, true),
+            Tested::Sparse(seen) => {
+                seen.insert(idx);
+            }
+        }
+    }
+}
+
+pub struct RlpIter<T> {
+    tested: Tested,
+    shift: T,
+    range: T,
+    numerator: T,
     pow: usize,
     final_pow: usize,
     state: State,
+    remaining: usize,
+    capped: bool,
 }
 
 // NOTE(nathan): This should be replaced with the builtin log2
 // once it is stabilized.
 //
 // https://github.com/rust-lang/rust/issues/70887
-fn ilog2(i: usize) -> usize {
-    (i as f64).log2().round() as usize
+fn ilog2<T: ToPrimitive>(i: T) -> usize {
+    i.to_f64().unwrap().log2().round() as usize
+}
+
+// Reverses the low `bits` bits of `k`, leaving the rest zeroed.
+fn bit_reverse(k: u64, bits: u32) -> u64 {
+    if bits == 0 {
+        return 0;
+    }
+
+    k.reverse_bits() >> (u64::BITS - bits)
+}
+
+// The number of bits needed so that `2^bits >= n`, i.e. ceil(log2(n)).
+fn bits_for(n: u64) -> u32 {
+    64 - n.saturating_sub(1).leading_zeros()
 }
 
-impl Iterator for RlpIter {
-    type Item = usize;
+// An upper bound on how many distinct values a capped `RlpIter` can emit:
+// never more than the range itself holds, and never more than the
+// `2^final_pow + 1` lattice fractions at this depth. Non-power-of-two
+// ranges can still round several fractions to the same value, so the true
+// emitted count can be lower than this bound - see `RlpIter::size_hint`.
+fn capped_bound<T: ToPrimitive>(range: T, final_pow: usize) -> usize {
+    let full = range.to_usize().unwrap() + 1;
+    let lattice = (1_usize << final_pow) + 1;
+
+    full.min(lattice)
+}
+
+// Shared by `rlp_iter_with_stride()` on the `Range`/`RangeInclusive` impls:
+// builds a stride-capped `RlpIter` with a `Sparse` dedup set sized to the
+// cap, instead of a `Dense` `BitVec` sized to the (possibly huge) range.
+fn rlp_iter_capped<T>(range: T, shift: T, pow_ceiling: usize) -> RlpIter<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    let final_pow = ilog2(range).min(pow_ceiling);
+    let remaining = capped_bound(range, final_pow);
+
+    RlpIter {
+        tested: Tested::Sparse(HashSet::with_capacity(remaining)),
+        shift,
+        range,
+        numerator: T::one(),
+        pow: 1,
+        final_pow,
+        state: State::Start,
+        remaining,
+        capped: true,
+    }
+}
+
+impl<T> Iterator for RlpIter<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         let unshifted = match self.state {
             State::Start => {
                 self.state = State::End;
-                self.tested.set(0, true);
-                Some(0)
+                self.tested.set(0);
+                Some(T::zero())
             }
             State::End => {
-                if self.range == 0 {
+                if self.range.is_zero() {
                     self.state = State::Finished;
                     None
                 } else {
                     self.state = State::Lattice;
-                    self.tested.set(self.range, true);
+                    self.tested.set(self.range.to_usize().unwrap());
                     Some(self.range)
                 }
             }
             State::Lattice => {
                 let mut out = None;
+                let range_f64 = self.range.to_f64().unwrap();
 
                 while self.pow <= self.final_pow {
                     // Calculate next value
                     let denominator = (1_u64 << self.pow) as usize;
-                    let val = (self.range as f64 * (self.numerator as f64 / denominator as f64))
-                        .round() as usize;
+                    let val = T::from_f64(
+                        (range_f64 * (self.numerator.to_f64().unwrap() / denominator as f64))
+                            .round(),
+                    )
+                    .unwrap();
+                    let val_idx = val.to_usize().unwrap();
 
-                    if !self.tested.get(val).unwrap() {
+                    if !self.tested.get(val_idx) {
                         out = Some(val);
-                        self.tested.set(val, true);
+                        self.tested.set(val_idx);
                     }
 
                     // Increment numerator / denominator
-                    if self.numerator == denominator - 1 {
-                        self.numerator = 1;
+                    if self.numerator.to_usize().unwrap() == denominator - 1 {
+                        self.numerator = T::one();
                         self.pow += 1;
                     } else {
-                        self.numerator += 1;
+                        self.numerator = self.numerator + T::one();
                     }
 
                     if out.is_some() {
@@ -123,17 +257,24 @@ impl Iterator for RlpIter {
 
                 if out.is_some() {
                     out
+                } else if self.capped {
+                    // The stride cap means we stop at the lattice depth
+                    // instead of falling through to a full gap fill.
+                    self.state = State::Finished;
+                    None
                 } else {
                     // Fill gaps with simple iteration
                     // This is equivalent to doing the next pow, but with less redundant checks
                     while self.numerator <= self.range {
-                        if !self.tested.get(self.numerator).unwrap() {
+                        let numerator_idx = self.numerator.to_usize().unwrap();
+
+                        if !self.tested.get(numerator_idx) {
                             out = Some(self.numerator);
-                            self.tested.set(self.numerator, true);
-                            self.numerator += 1;
+                            self.tested.set(numerator_idx);
+                            self.numerator = self.numerator + T::one();
                             break;
                         } else {
-                            self.numerator += 1;
+                            self.numerator = self.numerator + T::one();
                         }
                     }
 
@@ -148,40 +289,436 @@ impl Iterator for RlpIter {
             State::Finished => None,
         };
 
+        if unshifted.is_some() {
+            self.remaining -= 1;
+        }
+
         unshifted.map(|v| v + self.shift)
     }
+
+    // Only an uncapped run can promise this is exact: it's guaranteed to
+    // touch every value in the range exactly once by the time it
+    // finishes. A capped run stops short of that, and rounding collisions
+    // between lattice fractions mean the true number of values still to
+    // come can be lower than `remaining` - so it's reported as an upper
+    // bound only (lower bound 0), and `RlpIter` does not implement
+    // `ExactSizeIterator`, since that trait requires the bound to always
+    // be exact.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.capped {
+            (0, Some(self.remaining))
+        } else {
+            (self.remaining, Some(self.remaining))
+        }
+    }
+}
+
+impl<T> FusedIterator for RlpIter<T> where T: PrimInt + ToPrimitive + FromPrimitive + Integer {}
+
+impl<T> RlpIter<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    /// Caps the lattice subdivision depth at `pow_ceiling`, stopping before
+    /// the dense gap-fill phase instead of walking every value in the
+    /// range. This yields a bounded, evenly-spread sample of roughly
+    /// `2^pow_ceiling` values - useful when `range` is huge and only a
+    /// representative subset is needed. Has no effect if `pow_ceiling` is
+    /// already >= the depth the full range would use.
+    ///
+    /// Note that `rlp_iter()` has already allocated a `BitVec` sized to the
+    /// *full* range by the time this runs, so capping here only bounds the
+    /// traversal, not that allocation. To avoid paying for the allocation
+    /// at all, build the capped iterator directly with
+    /// `rlp_iter_with_stride()` instead.
+    pub fn with_stride(mut self, pow_ceiling: usize) -> Self {
+        if pow_ceiling < self.final_pow {
+            self.final_pow = pow_ceiling;
+            self.capped = true;
+            self.remaining = capped_bound(self.range, pow_ceiling);
+            self.tested = Tested::Sparse(HashSet::with_capacity(self.remaining));
+        }
+
+        self
+    }
 }
 
-pub trait RlpIterator {
-    fn rlp_iter(&self) -> RlpIter;
+/// A space-filling order over the same values as `RlpIter`, in O(log n)
+/// memory instead of one bit per value. Instead of tracking visited values
+/// in a `BitVec`, it walks a van der Corput / bit-reversal permutation of
+/// `0..2^bits`, which is a bijection, so every in-range value is produced
+/// exactly once with no dedup structure needed. Endpoints are emitted
+/// first, same as `RlpIter`, but the orders after them are not identical:
+/// `RlpIter` rounds fractions of the range, while this walks exact
+/// bit-reversed values (for `0..=7` this gives `0, 7, 4, 2, 6, 1, 5, 3`
+/// against `RlpIter`'s `0, 7, 4, 2, 5, 1, 3, 6`).
+pub struct RlpIterLowMem<T> {
+    shift: T,
+    range: T,
+    bits: u32,
+    k: u64,
+    state: State,
 }
 
-impl RlpIterator for Range<usize> {
-    fn rlp_iter(&self) -> RlpIter {
-        let range = self.end - self.start - 1;
+impl<T> Iterator for RlpIterLowMem<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let unshifted = match self.state {
+            State::Start => {
+                self.state = State::End;
+                Some(T::zero())
+            }
+            State::End => {
+                if self.range.is_zero() {
+                    self.state = State::Finished;
+                    None
+                } else {
+                    self.state = State::Lattice;
+                    Some(self.range)
+                }
+            }
+            State::Lattice => {
+                let range_idx = self.range.to_u64().unwrap();
+                let mut out = None;
+
+                while self.k < (1_u64 << self.bits) {
+                    let v = bit_reverse(self.k, self.bits);
+                    self.k += 1;
+
+                    // 0 and range were already emitted by Start/End, and
+                    // anything >= N is an artefact of bits not being an
+                    // exact fit for the range, so it must be skipped.
+                    if v != 0 && v != range_idx && v <= range_idx {
+                        out = Some(T::from_u64(v).unwrap());
+                        break;
+                    }
+                }
+
+                if out.is_none() {
+                    self.state = State::Finished;
+                }
+
+                out
+            }
+            State::Finished => None,
+        };
+
+        unshifted.map(|v| v + self.shift)
+    }
+}
+
+pub trait RlpIterator<T> {
+    fn rlp_iter(&self) -> RlpIter<T>;
+    fn rlp_iter_lowmem(&self) -> RlpIterLowMem<T>;
+
+    /// Like `rlp_iter().with_stride(pow_ceiling)`, but builds the capped
+    /// iterator directly instead of first allocating a `BitVec` sized to
+    /// the full range - the dedup set only ever needs to hold the roughly
+    /// `2^pow_ceiling` values a capped run actually emits.
+    ///
+    /// Unlike `with_stride()`, the cap always applies, even when
+    /// `pow_ceiling` is at or above the depth the full range would use: the
+    /// run then stops after the lattice at that depth with no gap fill, so
+    /// values the lattice rounds past are not emitted (`0..=11` yields 9 of
+    /// its 12 values).
+    fn rlp_iter_with_stride(&self, pow_ceiling: usize) -> RlpIter<T>;
+}
+
+impl<T> RlpIterator<T> for Range<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    fn rlp_iter(&self) -> RlpIter<T> {
+        let range = self.end - self.start - T::one();
         RlpIter {
-            tested: BitVec::from_elem(range + 1, false),
+            tested: Tested::Dense(BitVec::from_elem(range.to_usize().unwrap() + 1, false)),
             shift: self.start,
             range,
-            numerator: 1,
+            numerator: T::one(),
             pow: 1,
             final_pow: ilog2(range),
             state: State::Start,
+            remaining: range.to_usize().unwrap() + 1,
+            capped: false,
         }
     }
+
+    fn rlp_iter_lowmem(&self) -> RlpIterLowMem<T> {
+        let range = self.end - self.start - T::one();
+        RlpIterLowMem {
+            shift: self.start,
+            range,
+            bits: bits_for(range.to_u64().unwrap() + 1),
+            k: 0,
+            state: State::Start,
+        }
+    }
+
+    fn rlp_iter_with_stride(&self, pow_ceiling: usize) -> RlpIter<T> {
+        let range = self.end - self.start - T::one();
+        rlp_iter_capped(range, self.start, pow_ceiling)
+    }
 }
 
-impl RlpIterator for RangeInclusive<usize> {
-    fn rlp_iter(&self) -> RlpIter {
-        let range = self.end() - self.start();
+impl<T> RlpIterator<T> for RangeInclusive<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    fn rlp_iter(&self) -> RlpIter<T> {
+        let range = *self.end() - *self.start();
         RlpIter {
-            tested: BitVec::from_elem(range + 1, false),
+            tested: Tested::Dense(BitVec::from_elem(range.to_usize().unwrap() + 1, false)),
             shift: *self.start(),
             range,
-            numerator: 1,
+            numerator: T::one(),
             pow: 1,
             final_pow: ilog2(range),
             state: State::Start,
+            remaining: range.to_usize().unwrap() + 1,
+            capped: false,
+        }
+    }
+
+    fn rlp_iter_lowmem(&self) -> RlpIterLowMem<T> {
+        let range = *self.end() - *self.start();
+        RlpIterLowMem {
+            shift: *self.start(),
+            range,
+            bits: bits_for(range.to_u64().unwrap() + 1),
+            k: 0,
+            state: State::Start,
+        }
+    }
+
+    fn rlp_iter_with_stride(&self, pow_ceiling: usize) -> RlpIter<T> {
+        let range = *self.end() - *self.start();
+        rlp_iter_capped(range, *self.start(), pow_ceiling)
+    }
+}
+
+impl<T> RlpIterator<T> for RangeTo<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    fn rlp_iter(&self) -> RlpIter<T> {
+        (T::zero()..self.end).rlp_iter()
+    }
+
+    fn rlp_iter_lowmem(&self) -> RlpIterLowMem<T> {
+        (T::zero()..self.end).rlp_iter_lowmem()
+    }
+
+    fn rlp_iter_with_stride(&self, pow_ceiling: usize) -> RlpIter<T> {
+        (T::zero()..self.end).rlp_iter_with_stride(pow_ceiling)
+    }
+}
+
+impl<T> RlpIterator<T> for RangeToInclusive<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    fn rlp_iter(&self) -> RlpIter<T> {
+        (T::zero()..=self.end).rlp_iter()
+    }
+
+    fn rlp_iter_lowmem(&self) -> RlpIterLowMem<T> {
+        (T::zero()..=self.end).rlp_iter_lowmem()
+    }
+
+    fn rlp_iter_with_stride(&self, pow_ceiling: usize) -> RlpIter<T> {
+        (T::zero()..=self.end).rlp_iter_with_stride(pow_ceiling)
+    }
+}
+
+/// An infinite space-filling stream over `[start, ∞)`. Since there's no known
+/// upper bound to preallocate a `BitVec` against, this instead grows the
+/// sampled space by doubling: each time the conceptual bound doubles from
+/// `2^(pow-1)` to `2^pow`, the newly reachable block `[2^(pow-1), 2^pow)`
+/// is walked in bit-reversal order (the same permutation `RlpIterLowMem`
+/// uses) with the top bit flipped, so every previously emitted value stays
+/// fixed forever while the new block starts at its own midpoint and fills
+/// inward from there, instead of starting at the block's low edge.
+pub struct RlpIterUnbounded<T> {
+    shift: T,
+    pow: u32,
+    j: u64,
+    state: State,
+}
+
+impl<T> Iterator for RlpIterUnbounded<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let unshifted = match self.state {
+            State::Start => {
+                self.state = State::Lattice;
+                Some(0)
+            }
+            State::Lattice => {
+                let local_bits = self.pow - 1;
+                let block_start = 1_u64 << local_bits;
+
+                // Flipping the top bit of the bit-reversal permutation
+                // shifts its first output from the block's low edge (0) to
+                // its midpoint (block_start / 2), so the block still fills
+                // outward-in rather than edge-first. A block of size 1
+                // (`local_bits == 0`) has only one value and no bit to
+                // flip, so the flip is skipped there.
+                let offset = if local_bits == 0 {
+                    0
+                } else {
+                    bit_reverse(self.j, local_bits) ^ (1 << (local_bits - 1))
+                };
+                let val = block_start + offset;
+
+                self.j += 1;
+                if self.j >= block_start {
+                    self.pow += 1;
+                    self.j = 0;
+                }
+
+                Some(val)
+            }
+            State::End | State::Finished => None,
+        };
+
+        unshifted.map(|v| T::from_u64(v).unwrap() + self.shift)
+    }
+}
+
+/// Separate from `RlpIterator` because `RangeFrom` has no end to size a
+/// `BitVec` against - only `start..` implements this trait, so
+/// `use rlp_iter::RlpIterator;` alone does not bring `(0..).rlp_iter()`
+/// into scope. Import this trait as well (or instead) to iterate an
+/// unbounded range.
+pub trait RlpUnboundedIterator<T> {
+    fn rlp_iter(&self) -> RlpIterUnbounded<T>;
+}
+
+impl<T> RlpUnboundedIterator<T> for RangeFrom<T>
+where
+    T: PrimInt + ToPrimitive + FromPrimitive + Integer,
+{
+    fn rlp_iter(&self) -> RlpIterUnbounded<T> {
+        RlpIterUnbounded {
+            shift: self.start,
+            pow: 1,
+            j: 0,
+            state: State::Start,
+        }
+    }
+}
+
+// First 16 primes, used as the per-axis Halton bases. This caps RlpIterND at
+// 16 dimensions, which is far beyond anything a tile/parameter-space sampler
+// needs.
+const HALTON_PRIMES: [u64; 16] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53,
+];
+
+// The radical inverse of `k` in base `p`: write `k` in base `p` and mirror
+// its digits about the decimal point, giving a value in `[0, 1)`.
+fn radical_inverse(mut k: u64, base: u64) -> f64 {
+    let mut denominator = base as f64;
+    let mut inverse = 0.0;
+
+    while k > 0 {
+        inverse += (k % base) as f64 / denominator;
+        k /= base;
+        denominator *= base as f64;
+    }
+
+    inverse
+}
+
+/// A low-discrepancy sampler over a `D`-dimensional box `[0, ranges[0]) x
+/// [0, ranges[1]) x ...`, built from the Halton sequence (one prime base per
+/// axis). Unlike `RlpIter`, this does not guarantee exact coverage or an
+/// absence of duplicates for a bounded box, so it is an infinite stream
+/// meant to be `.take(n)`-ed; pair it with `.with_dedup()` if uniqueness
+/// matters more than speed.
+pub struct RlpIterND<const D: usize> {
+    ranges: [usize; D],
+    dedup: Option<BitVec>,
+    emitted: usize,
+    k: u64,
+}
+
+impl<const D: usize> RlpIterND<D> {
+    pub fn new(ranges: [usize; D]) -> Self {
+        assert!(D <= HALTON_PRIMES.len(), "RlpIterND supports at most 16 dimensions");
+        assert!(
+            ranges.iter().all(|&r| r > 0),
+            "RlpIterND axis lengths must be non-zero"
+        );
+
+        RlpIterND {
+            ranges,
+            dedup: None,
+            emitted: 0,
+            k: 0,
+        }
+    }
+
+    /// Skip points whose flattened box index has already been emitted,
+    /// backed by a `BitVec` sized to the box's total volume.
+    pub fn with_dedup(mut self) -> Self {
+        let volume = self.ranges.iter().product();
+        self.dedup = Some(BitVec::from_elem(volume, false));
+        self
+    }
+
+    fn flatten(ranges: &[usize; D], point: &[usize; D]) -> usize {
+        let mut idx = 0;
+
+        for d in 0..D {
+            idx = idx * ranges[d] + point[d];
+        }
+
+        idx
+    }
+}
+
+impl<const D: usize> Iterator for RlpIterND<D> {
+    type Item = [usize; D];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(tested) = &self.dedup {
+                if self.emitted >= tested.len() {
+                    return None;
+                }
+            }
+
+            let mut point = [0_usize; D];
+
+            for d in 0..D {
+                let phi = radical_inverse(self.k, HALTON_PRIMES[d]);
+                let coord = (phi * self.ranges[d] as f64).round() as usize;
+                point[d] = coord.min(self.ranges[d] - 1);
+            }
+
+            self.k += 1;
+
+            match &mut self.dedup {
+                Some(tested) => {
+                    let idx = Self::flatten(&self.ranges, &point);
+
+                    if !tested.get(idx).unwrap() {
+                        tested.set(idx, true);
+                        self.emitted += 1;
+                        return Some(point);
+                    }
+                }
+                None => return Some(point),
+            }
         }
     }
 }
@@ -250,4 +787,182 @@ mod tests {
 
         assert_eq!(out[0..9], [0, 100, 50, 25, 75, 13, 38, 63, 88]);
     }
+
+    #[test]
+    fn signed_range_works() {
+        let mut out: Vec<i64> = (-50_i64..=50).rlp_iter().collect();
+        let expected: Vec<i64> = (-50_i64..=50).collect();
+
+        out.sort();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn u32_range_works() {
+        let out: Vec<u32> = (0_u32..=8).rlp_iter().collect();
+        assert_eq!(out[..], [0, 8, 4, 2, 6, 1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn size_hint_tracks_remaining() {
+        let mut iter = (0..=8).rlp_iter();
+        assert_eq!(iter.size_hint(), (9, Some(9)));
+
+        for expected_remaining in (0..9).rev() {
+            iter.next();
+            assert_eq!(iter.size_hint(), (expected_remaining, Some(expected_remaining)));
+        }
+
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn lowmem_shares_start_and_end_with_rlp_iter() {
+        let out: Vec<usize> = (0..=7).rlp_iter_lowmem().take(2).collect();
+        assert_eq!(out[..], [0, 7]);
+    }
+
+    #[test]
+    fn lowmem_power_of_two_is_complete_with_no_skips() {
+        // N == 8 is an exact power of two, so every bit-reversed value is in range.
+        let mut out: Vec<usize> = (0..=7).rlp_iter_lowmem().collect();
+        let expected: Vec<usize> = (0..=7).collect();
+
+        out.sort();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn lowmem_inclusive_is_complete() {
+        let mut out: Vec<usize> = (7..=7919).rlp_iter_lowmem().collect();
+        let expected: Vec<usize> = (7..=7919).collect();
+
+        out.sort();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn lowmem_exclusive_is_complete() {
+        let mut out: Vec<usize> = (7..7919).rlp_iter_lowmem().collect();
+        let expected: Vec<usize> = (7..7919).collect();
+
+        out.sort();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn range_to_treats_start_as_zero() {
+        let mut out: Vec<usize> = (..9).rlp_iter().collect();
+        let expected: Vec<usize> = (0..9).collect();
+
+        out.sort();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn range_to_inclusive_treats_start_as_zero() {
+        let mut out: Vec<usize> = (..=8).rlp_iter().collect();
+        let expected: Vec<usize> = (0..=8).collect();
+
+        out.sort();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn range_from_is_infinite_and_space_filling() {
+        use crate::RlpUnboundedIterator;
+
+        let out: Vec<usize> = (0..).rlp_iter().take(8).collect();
+        assert_eq!(out[..], [0, 1, 3, 2, 6, 4, 7, 5]);
+    }
+
+    #[test]
+    fn range_from_offset_works() {
+        use crate::RlpUnboundedIterator;
+
+        let out: Vec<usize> = (100..).rlp_iter().take(4).collect();
+        assert_eq!(out[..], [100, 101, 103, 102]);
+    }
+
+    #[test]
+    fn nd_points_stay_within_the_box() {
+        use crate::RlpIterND;
+
+        let points: Vec<[usize; 2]> = RlpIterND::new([64, 128]).take(500).collect();
+
+        for [x, y] in points {
+            assert!(x < 64);
+            assert!(y < 128);
+        }
+    }
+
+    #[test]
+    fn nd_with_dedup_never_repeats() {
+        use crate::RlpIterND;
+        use std::collections::HashSet;
+
+        let points: Vec<[usize; 2]> = RlpIterND::new([8, 8]).with_dedup().take(64).collect();
+        let unique: HashSet<[usize; 2]> = points.iter().copied().collect();
+
+        assert_eq!(points.len(), unique.len());
+    }
+
+    #[test]
+    fn nd_with_dedup_terminates_once_the_box_is_full() {
+        use crate::RlpIterND;
+        use std::collections::HashSet;
+
+        let points: Vec<[usize; 2]> = RlpIterND::new([2, 2]).with_dedup().collect();
+        let unique: HashSet<[usize; 2]> = points.iter().copied().collect();
+
+        assert_eq!(points.len(), 4);
+        assert_eq!(unique.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nd_rejects_a_zero_length_axis() {
+        use crate::RlpIterND;
+
+        RlpIterND::new([0, 5]);
+    }
+
+    #[test]
+    fn with_stride_caps_the_lattice_depth() {
+        let out: Vec<usize> = (0..1_000_000_000).rlp_iter().with_stride(4).collect();
+
+        assert_eq!(out.len(), 17);
+        assert_eq!(out[0], 0);
+        assert_eq!(out[1], 999_999_999);
+    }
+
+    #[test]
+    fn with_stride_on_a_small_non_power_of_two_range_does_not_overcount() {
+        // N = 7 is not a power of two, so the naive `2^final_pow + 1` bound
+        // (9 here) overcounts what the range itself can hold (7).
+        let iter = (0..7).rlp_iter_with_stride(3);
+        let upper_bound = iter.size_hint().1.unwrap();
+
+        let mut out: Vec<usize> = iter.collect();
+        out.sort();
+
+        assert_eq!(out, (0..7).collect::<Vec<_>>());
+        assert_eq!(upper_bound, 7);
+    }
+
+    #[test]
+    fn with_stride_is_a_no_op_above_the_natural_depth() {
+        let capped: Vec<usize> = (0..=8).rlp_iter().with_stride(100).collect();
+        let uncapped: Vec<usize> = (0..=8).rlp_iter().collect();
+
+        assert_eq!(capped, uncapped);
+    }
+
+    #[test]
+    fn rlp_iter_with_stride_matches_with_stride() {
+        let direct: Vec<usize> = (0..1_000_000_000).rlp_iter_with_stride(4).collect();
+        let adapted: Vec<usize> = (0..1_000_000_000).rlp_iter().with_stride(4).collect();
+
+        assert_eq!(direct, adapted);
+    }
 }